@@ -0,0 +1,224 @@
+//! Named curve presets and ECDSA built on top of [`Point`] and
+//! [`FieldElement`].
+//!
+//! Signing and verification need two different modular worlds: point
+//! arithmetic on the curve itself (`Point<T>`, unreduced) and arithmetic in
+//! the scalar field of order `n` (`FieldElement<T>`, reduced mod `n`). `T`
+//! therefore has to satisfy both `Number` traits.
+//!
+//! `Point<T>` never reduces its `x`/`y` coordinates modulo a field prime
+//! (see [`Point::scalar_mul`]): every `+` and `*` on it is raw `T`
+//! arithmetic. That means `keygen`/`sign`/`verify` below only stay on the
+//! curve for a `T` that is *already* a reduced field element under the
+//! hood (so repeated doubling can't drift), not for a plain unreduced
+//! integer like `i64` — for those, almost every scalar eventually produces
+//! an intermediate point that fails the curve equation and panics. There
+//! is currently no `Number`-compatible reduced integer type in this crate
+//! (see the module doc of [`field`](crate::primitives::field) for
+//! why `PrimeField` can't fill that role yet), so treat this subsystem as
+//! a demonstration of the ECDSA math, not a production-ready API.
+//!
+//! In particular, [`Curve::secp256k1`] below is scaffolding, not a usable
+//! secp256k1: no `T` this crate ships — primitive or `PrimeField` — can
+//! reach it, so there is no end-to-end path to real secp256k1 keys here
+//! today.
+
+use num::traits::Euclid;
+
+use super::point::{Number as PointNumber, Point};
+use crate::primitives::field_element::{FieldElement, Number as FieldNumber};
+
+/// An ECDSA signature, the pair `(r, s)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Signature<T> {
+    pub r: T,
+    pub s: T,
+}
+
+/// A short Weierstrass curve `y^2 = x^3 + a*x + b`, together with a
+/// generator `g` and the order `n` of the subgroup `g` generates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Curve<T> {
+    pub a: T,
+    pub b: T,
+    pub g: Point<T>,
+    pub n: T,
+}
+
+impl<T> Curve<T>
+where
+    T: PointNumber + FieldNumber,
+{
+    /// Derives the public key `d * G` for private key `d`.
+    pub fn keygen(&self, private_key: T) -> Point<T> {
+        self.g * private_key
+    }
+
+    /// Signs `message_hash` with `private_key`, using `k` as the
+    /// per-signature nonce.
+    ///
+    /// `k` must be sampled uniformly at random and never reused across
+    /// signatures for the same key: a repeated or predictable `k` leaks the
+    /// private key. This crate doesn't pull in a CSPRNG, so nonce generation
+    /// is left to the caller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.g * k` lands on the point at infinity, or — per the
+    /// module-level caveat above — if `T`'s unreduced arithmetic drifts
+    /// `self.g * k` or `self.g * private_key` off the curve before this
+    /// function ever sees them (surfaced from deep inside [`Point`]'s own
+    /// on-curve check).
+    pub fn sign(&self, message_hash: T, private_key: T, k: T) -> Signature<T> {
+        let r = match self.g * k {
+            Point::Point { x, .. } => x.rem_euclid(&self.n),
+            Point::Infinite { .. } => {
+                panic!("nonce k produced the point at infinity, choose a different k")
+            }
+        };
+
+        let k_inv =
+            FieldElement::new(T::one(), self.n).unwrap() / FieldElement::new(k, self.n).unwrap();
+        let z = FieldElement::new(message_hash.rem_euclid(&self.n), self.n).unwrap();
+        let r_fe = FieldElement::new(r, self.n).unwrap();
+        let d = FieldElement::new(private_key, self.n).unwrap();
+
+        let s = k_inv * (z + r_fe * d);
+
+        Signature { r, s: s.value() }
+    }
+
+    /// Verifies that `signature` was produced by the holder of `public_key`
+    /// over `message_hash`.
+    ///
+    /// # Panics
+    ///
+    /// Same caveat as [`Curve::sign`]: panics if `T`'s unreduced arithmetic
+    /// drifts an intermediate point off the curve.
+    pub fn verify(&self, message_hash: T, signature: Signature<T>, public_key: Point<T>) -> bool {
+        let s_inv = FieldElement::new(T::one(), self.n).unwrap()
+            / FieldElement::new(signature.s, self.n).unwrap();
+        let z = FieldElement::new(message_hash.rem_euclid(&self.n), self.n).unwrap();
+        let r_fe = FieldElement::new(signature.r, self.n).unwrap();
+
+        let u1 = (z * s_inv).value();
+        let u2 = (r_fe * s_inv).value();
+
+        match (self.g * u1) + (public_key * u2) {
+            Point::Point { x, .. } => x.rem_euclid(&self.n) == signature.r,
+            Point::Infinite { .. } => false,
+        }
+    }
+}
+
+impl<T> Curve<T>
+where
+    T: PointNumber + FieldNumber + num::Num,
+{
+    /// The secp256k1 curve: `y^2 = x^3 + 7` over `F_p` with
+    /// `p = 2^256 - 2^32 - 977`, generator `G`, and subgroup order `n`.
+    ///
+    /// The coefficients below are secp256k1's real 256-bit constants, which
+    /// don't fit in any primitive `Number` this crate ships today (`u128`
+    /// at most), so this is a documented no-op for every `T` currently
+    /// available: `PrimeField` (see
+    /// [`field::bigint`](crate::primitives::field::bigint)) carries enough
+    /// bits but can't implement `Number` itself (it's backed by `BigUint`,
+    /// which isn't `Copy`), so there is no `T` today — primitive or
+    /// arbitrary-precision — that actually reaches this constructor.
+    pub fn secp256k1() -> Option<Self> {
+        let a = T::zero();
+        let b = T::from_str_radix("7", 16).ok()?;
+        let gx = T::from_str_radix(
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16,
+        )
+        .ok()?;
+        let gy = T::from_str_radix(
+            "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16,
+        )
+        .ok()?;
+        let n = T::from_str_radix(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16,
+        )
+        .ok()?;
+
+        let g = Point::new(Some(gx), Some(gy), a, b).ok()?;
+        Some(Curve { a, b, g, n })
+    }
+}
+
+// `toy_curve()` below uses `i64` as `T`, which only implements
+// `field_element::Number`'s signed operations (and, transitively, the
+// `Number` bound `Point`/`Curve` need) behind `signed_field_elements` — see
+// the same gate on `pow_works_with_negative_exponential` in
+// `field_element.rs`. Without it, a default-feature `cargo test` fails to
+// compile this module.
+#[cfg(all(test, feature = "signed_field_elements"))]
+mod tests {
+    use super::*;
+
+    // A small toy curve used to exercise the sign/verify round trip:
+    // `y^2 = x^3 - 3x + 3` over the integers, generator (1, 1).
+    //
+    // `Point` doesn't reduce mod a prime and its integer division truncates,
+    // so intermediate points generally drift off the curve; `n` here is
+    // just a scalar-field modulus for the signature math, not a claim about
+    // this generator's true (unbounded) order. `k = 3` below is one of the
+    // few nonces for which the points the ladder adds happen to stay on the
+    // curve for this generator — most nonces drift the intermediate point
+    // off the curve and panic, per the module-level caveat.
+    fn toy_curve() -> Curve<i64> {
+        let g = Point::new(Some(1), Some(1), -3, 3).unwrap();
+        Curve {
+            a: -3,
+            b: 3,
+            g,
+            n: 97,
+        }
+    }
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let curve = toy_curve();
+        let private_key = 1;
+        let public_key = curve.keygen(private_key);
+
+        let message_hash = 0;
+        let k = 3;
+        let signature = curve.sign(message_hash, private_key, k);
+
+        assert!(curve.verify(message_hash, signature, public_key));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not on the curve")]
+    fn sign_panics_when_unreduced_coordinates_drift_off_curve() {
+        // Demonstrates the module-level caveat: for this toy curve, `k = 3`
+        // is the exception, not the rule. Most nonces make `scalar_mul`
+        // walk `x`/`y` to a point that no longer satisfies the curve
+        // equation, since `i64` arithmetic here is never reduced mod a
+        // prime.
+        let curve = toy_curve();
+        let private_key = 1;
+        curve.sign(0, private_key, 4);
+    }
+
+    #[test]
+    fn verify_rejects_wrong_message() {
+        let curve = toy_curve();
+        let private_key = 1;
+        let public_key = curve.keygen(private_key);
+
+        let signature = curve.sign(0, private_key, 3);
+
+        assert!(!curve.verify(1, signature, public_key));
+    }
+
+    #[test]
+    fn secp256k1_preset_is_out_of_reach_for_primitive_scalars() {
+        assert_eq!(Curve::<i64>::secp256k1(), None);
+    }
+}
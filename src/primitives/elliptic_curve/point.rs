@@ -168,7 +168,7 @@ impl<T: Number> std::ops::Add for Point<T> {
                 let three = two + one;
 
                 let s = (three * x.pow(2_u8) + a) / (two * y);
-                let new_x = s.pow(2_u8) * x - rhs_x;
+                let new_x = s.pow(2_u8) - x - rhs_x;
                 let new_y = s * (x - new_x) - y;
                 Point::Point {
                     a,
@@ -201,6 +201,256 @@ impl<T: Number> std::ops::Add for Point<T> {
     }
 }
 
+impl<T: Number> Point<T> {
+    /// Computes `k * self` using the double-and-add ladder.
+    ///
+    /// `k == 0` (or the identity) yields the point at infinity, since
+    /// repeated addition of `self` zero times leaves the accumulator
+    /// untouched. Adding infinity is already a no-op via the `Add` impl.
+    pub fn scalar_mul(self, k: T) -> Self {
+        let (a, b) = match self {
+            Self::Point { a, b, .. } => (a, b),
+            Self::Infinite { a, b } => (a, b),
+        };
+
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+
+        let mut acc = Self::Infinite { a, b };
+        let mut base = self;
+        let mut k = k;
+
+        while k != zero {
+            if k % two == one {
+                acc = acc + base;
+            }
+            base = base + base;
+            k = k >> one;
+        }
+
+        acc
+    }
+}
+
+impl<T: Number> std::ops::Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self::Output {
+        self.scalar_mul(k)
+    }
+}
+
+/// Constant-time comparison and selection, so scalar multiplication with a
+/// secret scalar doesn't leak it through data-dependent branching.
+#[cfg(feature = "constant_time")]
+mod constant_time {
+    use super::{Number, Point};
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+    impl<T: Number + ConstantTimeEq> ConstantTimeEq for Point<T> {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            match (self, other) {
+                (Point::Infinite { a, b }, Point::Infinite { a: oa, b: ob }) => {
+                    a.ct_eq(oa) & b.ct_eq(ob)
+                }
+                (
+                    Point::Point { a, b, x, y },
+                    Point::Point {
+                        a: oa,
+                        b: ob,
+                        x: ox,
+                        y: oy,
+                    },
+                ) => a.ct_eq(oa) & b.ct_eq(ob) & x.ct_eq(ox) & y.ct_eq(oy),
+                _ => Choice::from(0),
+            }
+        }
+    }
+
+    impl<T: Number + ConditionallySelectable> ConditionallySelectable for Point<T> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            // The enum's variant tag can't be hidden this way, so this impl
+            // is only safe to call with two values of the same variant.
+            // `CtPoint` below is what guarantees that for the double-and-add
+            // ladder, by keeping the point-at-infinity case out of `Point`'s
+            // variant entirely; callers outside this module should do the
+            // same rather than pass mismatched variants here.
+            match (a, b) {
+                (Point::Infinite { a: aa, b: ab }, Point::Infinite { a: ba, b: bb }) => {
+                    Point::Infinite {
+                        a: T::conditional_select(aa, ba, choice),
+                        b: T::conditional_select(ab, bb, choice),
+                    }
+                }
+                (
+                    Point::Point {
+                        a: aa,
+                        b: ab,
+                        x: ax,
+                        y: ay,
+                    },
+                    Point::Point {
+                        a: ba,
+                        b: bb,
+                        x: bx,
+                        y: by,
+                    },
+                ) => Point::Point {
+                    a: T::conditional_select(aa, ba, choice),
+                    b: T::conditional_select(ab, bb, choice),
+                    x: T::conditional_select(ax, bx, choice),
+                    y: T::conditional_select(ay, by, choice),
+                },
+                _ => {
+                    if bool::from(choice) {
+                        *b
+                    } else {
+                        *a
+                    }
+                }
+            }
+        }
+    }
+
+    /// A uniform stand-in for `Point<T>` used only inside the double-and-add
+    /// ladder: `Point`'s `Infinite` vs `Point` variants can't be told apart
+    /// in constant time, so a secret-dependent `conditional_select` between
+    /// a running accumulator that starts at infinity and one that doesn't
+    /// would fall into the mismatched-variant branch above — an ordinary
+    /// `if`, i.e. exactly the data-dependent branch this module exists to
+    /// avoid.
+    ///
+    /// `CtPoint` sidesteps that by never representing infinity as a
+    /// different shape: coordinates are always a real point (`placeholder`
+    /// is substituted in), and a separate `is_infinity` `Choice` carries
+    /// what the variant tag would have. Two `CtPoint`s are therefore always
+    /// the same shape, so `conditional_select` on them never takes a
+    /// secret-dependent branch.
+    ///
+    /// This only closes the specific leak above; two narrower timing
+    /// signals remain, neither addressed here:
+    ///
+    /// - the underlying point addition formula (inherited from `Point`'s
+    ///   `Add` impl) still branches on whether the two operands are equal
+    ///   (doubling case) or one another's negation (result-is-infinity
+    ///   case);
+    /// - [`Point::scalar_mul_ct`]'s double-and-add loop runs a number of
+    ///   iterations proportional to `k`'s bit length, leaking the scalar's
+    ///   approximate magnitude — arguably the more obvious of the two,
+    ///   since it's visible from total call duration alone, with no need
+    ///   to measure individual steps.
+    #[derive(Clone, Copy)]
+    struct CtPoint<T> {
+        point: Point<T>,
+        is_infinity: Choice,
+    }
+
+    impl<T: Number> CtPoint<T> {
+        /// Wraps `point`, using `placeholder` (a fixed, publicly-known,
+        /// genuine point on the curve) as the stand-in coordinates when
+        /// `point` is `Point::Infinite`.
+        fn new(point: Point<T>, placeholder: Point<T>) -> Self {
+            match point {
+                Point::Point { .. } => CtPoint {
+                    point,
+                    is_infinity: Choice::from(0),
+                },
+                Point::Infinite { .. } => CtPoint {
+                    point: placeholder,
+                    is_infinity: Choice::from(1),
+                },
+            }
+        }
+
+        /// Unwraps back to a real `Point`, restoring the `Infinite` variant
+        /// when `is_infinity` is set.
+        fn into_point(self) -> Point<T> {
+            let (a, b) = match self.point {
+                Point::Point { a, b, .. } => (a, b),
+                Point::Infinite { a, b } => (a, b),
+            };
+            if bool::from(self.is_infinity) {
+                Point::Infinite { a, b }
+            } else {
+                self.point
+            }
+        }
+    }
+
+    impl<T: Number + ConditionallySelectable> ConditionallySelectable for CtPoint<T> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            CtPoint {
+                point: Point::conditional_select(&a.point, &b.point, choice),
+                is_infinity: Choice::conditional_select(&a.is_infinity, &b.is_infinity, choice),
+            }
+        }
+    }
+
+    impl<T: Number + ConditionallySelectable> CtPoint<T> {
+        /// Adds two `CtPoint`s, resolving the point-at-infinity identity
+        /// law (`inf + q = q`, `p + inf = p`) via `conditional_select`
+        /// instead of matching on which side is infinite.
+        fn ct_add(self, rhs: Self) -> Self {
+            // Both sides always wrap a genuine `Point::Point`, so this never
+            // hits `Point::Add`'s validity panic regardless of either
+            // side's `is_infinity` flag.
+            let sum = self.point + rhs.point;
+            let direct = CtPoint::new(sum, self.point);
+
+            let or_self_if_rhs_infinite =
+                CtPoint::conditional_select(&direct, &self, rhs.is_infinity);
+            CtPoint::conditional_select(&or_self_if_rhs_infinite, &rhs, self.is_infinity)
+        }
+    }
+
+    impl<T> Point<T>
+    where
+        T: Number + ConstantTimeEq + ConditionallySelectable,
+    {
+        /// The same double-and-add ladder as `scalar_mul`, but the
+        /// add-or-not step is chosen via `conditional_select` instead of
+        /// branching on the scalar's bits, so every iteration performs the
+        /// same operations whether the current bit is 0 or 1. The number of
+        /// iterations still follows `k`'s bit length, and the point addition
+        /// itself still branches (see `CtPoint`). Use this instead of
+        /// `scalar_mul` whenever `k` is a secret (e.g. a private key).
+        pub fn scalar_mul_ct(self, k: T) -> Self {
+            // The identity has no non-infinite point to use as `CtPoint`'s
+            // placeholder, but it's also the only case where the result
+            // doesn't depend on `k` at all (k * infinity == infinity for
+            // every k), so it's not a secret-dependent branch to special
+            // case here.
+            let Self::Point { .. } = self else {
+                return self;
+            };
+
+            let (a, b) = match self {
+                Self::Point { a, b, .. } => (a, b),
+                Self::Infinite { a, b } => (a, b),
+            };
+
+            let zero = T::zero();
+            let one = T::one();
+            let two = one + one;
+
+            let mut acc = CtPoint::new(Self::Infinite { a, b }, self);
+            let mut base = CtPoint::new(self, self);
+            let mut k = k;
+
+            while k != zero {
+                let bit_is_one = (k % two).ct_eq(&one);
+                let added = acc.ct_add(base);
+                acc = CtPoint::conditional_select(&acc, &added, bit_is_one);
+                base = base.ct_add(base);
+                k = k >> one;
+            }
+
+            acc.into_point()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,4 +476,42 @@ mod tests {
             Err(Error::NotOnCurve(-1, -2))
         );
     }
+
+    #[test]
+    fn scalar_mul_by_zero_is_infinity() {
+        let p = Point::new(Some(-1), Some(-1), 5, 7).unwrap();
+        assert_eq!(p.scalar_mul(0), Point::Infinite { a: 5, b: 7 });
+    }
+
+    #[test]
+    fn scalar_mul_by_one_is_identity() {
+        let p = Point::new(Some(-1), Some(-1), 5, 7).unwrap();
+        assert_eq!(p.scalar_mul(1), p);
+    }
+
+    #[test]
+    fn scalar_mul_matches_repeated_addition() {
+        let p = Point::new(Some(-1), Some(-1), 5, 7).unwrap();
+        assert_eq!(p.scalar_mul(2), p + p);
+        assert_eq!(p * 2, p + p);
+    }
+
+    #[cfg(feature = "constant_time")]
+    #[test]
+    fn scalar_mul_ct_matches_scalar_mul() {
+        let p = Point::new(Some(-1), Some(-1), 5, 7).unwrap();
+        // k = 1, 3 (low bit 1) and k = 2 (low bit 0) exercise both
+        // outcomes of the first ladder iteration, where the accumulator
+        // starts at infinity; k = 0 skips the loop entirely.
+        for k in [0, 1, 2, 3] {
+            assert_eq!(p.scalar_mul_ct(k), p.scalar_mul(k), "mismatch for k = {k}");
+        }
+    }
+
+    #[cfg(feature = "constant_time")]
+    #[test]
+    fn scalar_mul_ct_of_infinity_is_infinity() {
+        let p = Point::new(None, None, 5, 7).unwrap();
+        assert_eq!(p.scalar_mul_ct(3), Point::Infinite { a: 5, b: 7 });
+    }
 }
@@ -0,0 +1,228 @@
+//! A field abstraction that both the primitive-backed [`FieldElement`] and
+//! the arbitrary-precision [`PrimeField`] below can implement.
+//!
+//! [`FieldElement<T>`] is capped at whatever primitive `Number` type backs
+//! it (`u128` at most), which cannot hold the 256-bit modulus secp256k1
+//! needs or the 448-bit prime ed448 uses. [`PrimeField<P>`] fills that gap
+//! by carrying the value as a `num::BigUint` and the modulus on the type
+//! via [`PrimeFieldParams`], following the same split the `bn` crate uses.
+//!
+//! The BigUint path lives behind the `bigint_field` Cargo feature so crates
+//! that only need the primitive path don't pay for it.
+//!
+//! `PrimeField<P>` does *not* implement [`elliptic_curve::point::Number`] or
+//! [`field_element::Number`] (both require `Copy`, among other bounds
+//! `BigUint` can't satisfy), so `Curve<T>` can't be instantiated with it —
+//! `secp256k1()` in `elliptic_curve::curve` stays unreachable for every `T`
+//! this crate ships today, not just "until `PrimeField` also implements
+//! `Number`". Bridging the two would mean reworking `PrimeField` onto a
+//! fixed-width, `Copy`-able big integer instead of `BigUint`, which is out
+//! of scope here; this module is groundwork for that, not a finished path
+//! to real secp256k1 keys.
+//!
+//! Concretely: nothing in this crate can do end-to-end secp256k1 ECDSA
+//! today. `PrimeField` is the only type with room for secp256k1-sized
+//! values, and it can't plug into `Curve<T>`; `Curve<T>` is the only type
+//! that can drive ECDSA, and every `T` it accepts is too small for real
+//! secp256k1. Treat this module as an isolated `Field`/`PrimeField`
+//! scaffold, not a delivered crypto capability.
+//!
+//! [`elliptic_curve::point::Number`]: crate::primitives::elliptic_curve::point::Number
+//! [`field_element::Number`]: crate::primitives::field_element::Number
+
+/// Shared behaviour of a finite field.
+///
+/// `FieldElement<T>` carries its modulus per instance rather than on the
+/// type, so `zero()`/`one()` take `&self` (read as "the identity of the
+/// same field as this element") rather than being modulus-free associated
+/// functions; `PrimeField<P>` below ignores `self` and reads its modulus
+/// from `P` instead, since it carries the modulus on the type.
+pub trait Field:
+    Sized
+    + Clone
+    + PartialEq
+    + std::ops::Add<Self, Output = Self>
+    + std::ops::Sub<Self, Output = Self>
+    + std::ops::Mul<Self, Output = Self>
+{
+    /// The additive identity of this element's field.
+    fn zero(&self) -> Self;
+    /// The multiplicative identity of this element's field.
+    fn one(&self) -> Self;
+    /// Whether this element is the additive identity.
+    fn is_zero(&self) -> bool;
+    /// The multiplicative inverse. Panics for the zero element, which has
+    /// none.
+    fn inverse(&self) -> Self;
+}
+
+#[cfg(feature = "bigint_field")]
+pub mod bigint {
+    use super::Field;
+    use num::{BigUint, One, Zero};
+    use std::marker::PhantomData;
+
+    /// Carries the modulus (and related constants) for a [`PrimeField`] on
+    /// the type, rather than duplicating it in every element the way
+    /// `FieldElement` carries its prime alongside each value.
+    pub trait PrimeFieldParams: Clone + std::fmt::Debug + PartialEq {
+        /// The field's prime modulus.
+        fn modulus() -> BigUint;
+        /// Bit length of the modulus, e.g. 256 for secp256k1.
+        fn bits() -> u32;
+        /// Human-readable name of the field, e.g. "secp256k1".
+        fn name() -> &'static str;
+    }
+
+    /// An element of the prime field described by `P`, backed by
+    /// [`BigUint`] so moduli larger than `u128` (secp256k1, ed448, ...) are
+    /// representable.
+    #[derive(Clone, Debug)]
+    pub struct PrimeField<P: PrimeFieldParams> {
+        value: BigUint,
+        _params: PhantomData<P>,
+    }
+
+    impl<P: PrimeFieldParams> PrimeField<P> {
+        /// Reduces `value` modulo `P::modulus()` and wraps it.
+        pub fn new(value: BigUint) -> Self {
+            PrimeField {
+                value: value % P::modulus(),
+                _params: PhantomData,
+            }
+        }
+
+        pub fn value(&self) -> &BigUint {
+            &self.value
+        }
+    }
+
+    impl<P: PrimeFieldParams> PartialEq for PrimeField<P> {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl<P: PrimeFieldParams> std::ops::Add for PrimeField<P> {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            PrimeField::new(self.value + rhs.value)
+        }
+    }
+
+    impl<P: PrimeFieldParams> std::ops::Sub for PrimeField<P> {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            // Add the modulus first so the subtraction never underflows,
+            // mirroring the modular negation used by FieldElement's Sub.
+            PrimeField::new(P::modulus() + self.value - rhs.value)
+        }
+    }
+
+    impl<P: PrimeFieldParams> std::ops::Mul for PrimeField<P> {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            PrimeField::new(self.value * rhs.value)
+        }
+    }
+
+    impl<P: PrimeFieldParams> std::ops::Div for PrimeField<P> {
+        type Output = Self;
+
+        // Division in a field is multiplication by the inverse, not a
+        // typo'd `self * rhs` — clippy can't tell the two apart here.
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self::Output {
+            self * rhs.inverse()
+        }
+    }
+
+    impl<P: PrimeFieldParams> Field for PrimeField<P> {
+        fn zero(&self) -> Self {
+            PrimeField::new(BigUint::zero())
+        }
+
+        fn one(&self) -> Self {
+            PrimeField::new(BigUint::one())
+        }
+
+        fn is_zero(&self) -> bool {
+            self.value.is_zero()
+        }
+
+        fn inverse(&self) -> Self {
+            if self.is_zero() {
+                panic!("the zero element has no multiplicative inverse")
+            }
+
+            // Fermat's little theorem: a^(p-2) mod p == a^-1 mod p.
+            let p = P::modulus();
+            let exp = &p - BigUint::from(2_u8);
+            PrimeField::new(self.value.modpow(&exp, &p))
+        }
+    }
+
+    /// The base field modulus for secp256k1:
+    /// `2^256 - 2^32 - 977`.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Secp256k1Params;
+
+    impl PrimeFieldParams for Secp256k1Params {
+        fn modulus() -> BigUint {
+            BigUint::parse_bytes(
+                b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+                16,
+            )
+            .expect("secp256k1 modulus is a valid hex literal")
+        }
+
+        fn bits() -> u32 {
+            256
+        }
+
+        fn name() -> &'static str {
+            "secp256k1"
+        }
+    }
+
+    /// The secp256k1 base field, `F_p` with `p = 2^256 - 2^32 - 977`.
+    pub type Secp256k1Field = PrimeField<Secp256k1Params>;
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn add_sub_roundtrip() {
+            let a = PrimeField::<Secp256k1Params>::new(BigUint::from(5_u8));
+            let b = PrimeField::<Secp256k1Params>::new(BigUint::from(3_u8));
+            assert_eq!(a.clone() + b.clone() - b, a);
+        }
+
+        #[test]
+        fn inverse_works() {
+            let a = PrimeField::<Secp256k1Params>::new(BigUint::from(7_u8));
+            let inv = a.inverse();
+            assert_eq!(a.clone() * inv, a.one());
+        }
+
+        #[test]
+        #[should_panic(expected = "the zero element has no multiplicative inverse")]
+        fn inverse_panics_for_zero() {
+            PrimeField::<Secp256k1Params>::new(BigUint::zero()).inverse();
+        }
+
+        #[test]
+        fn sub_does_not_underflow_when_rhs_is_larger() {
+            let a = PrimeField::<Secp256k1Params>::new(BigUint::from(3_u8));
+            let b = PrimeField::<Secp256k1Params>::new(BigUint::from(5_u8));
+            let diff = a - b;
+            let expected = PrimeField::<Secp256k1Params>::new(BigUint::from(3_u8));
+            let five = PrimeField::<Secp256k1Params>::new(BigUint::from(5_u8));
+            assert_eq!(diff + five, expected);
+        }
+    }
+}
@@ -89,6 +89,7 @@ impl<T: std::fmt::Debug> std::error::Error for Error<T> {}
 /// 5. If a is in the set and is not 0, a^-1 is in the set where a*a^-1 = 1. (Multiplicative inverse)
 ///
 /// The mathematical notation is Fp = {0,1,2,...p-1}.
+#[derive(Clone, Copy)]
 pub struct FieldElement<T>(T, T);
 
 // Constructor with trait bounds.
@@ -104,6 +105,16 @@ where
         Ok(fe)
     }
 
+    /// The element's value in `0..prime`.
+    pub fn value(&self) -> T {
+        self.0
+    }
+
+    /// The field's prime modulus.
+    pub fn prime(&self) -> T {
+        self.1
+    }
+
     fn has_valid_range(&self, panic: bool) -> bool {
         if self.0 >= self.1 || self.0 < T::default() {
             if panic {
@@ -136,6 +147,50 @@ where
 
         FieldElement(num, self.1)
     }
+
+    /// Inverts every element of `elems` using Montgomery's batch inversion
+    /// trick, so a slice of N elements costs a single `mod_exp` instead of N.
+    ///
+    /// All elements must belong to the same field (share the same prime),
+    /// otherwise this panics the same way the binary operators do. Elements
+    /// equal to zero have no inverse, so they are left untouched and excluded
+    /// from the running product.
+    pub fn batch_invert(elems: &mut [FieldElement<T>]) {
+        let Some(prime) = elems.first().map(|e| e.1) else {
+            return;
+        };
+
+        // Running prefix products: prefix[i] = a_0 * a_1 * ... * a_{i-1},
+        // skipping any zero element so it can't zero out the whole product.
+        let mut prefixes = Vec::with_capacity(elems.len());
+        let mut acc = T::one();
+        for e in elems.iter() {
+            e.__ensure_valid_range();
+            if e.1 != prime {
+                panic!("Sides are of different fields")
+            }
+
+            prefixes.push(acc);
+            if e.0 != T::zero() {
+                acc = (acc * e.0) % prime;
+            }
+        }
+
+        // Invert the total product once via Fermat's little theorem.
+        let two = T::one() + T::one();
+        let mut acc_inv = mod_exp(acc, prime - two, prime);
+
+        // Walk backward, peeling off each element's inverse from the running
+        // inverse and rolling it back to the product of the elements before it.
+        for (e, prefix) in elems.iter_mut().zip(prefixes).rev() {
+            if e.0 == T::zero() {
+                continue;
+            }
+            let inverse = (acc_inv * prefix) % prime;
+            acc_inv = (acc_inv * e.0) % prime;
+            *e = FieldElement(inverse, prime);
+        }
+    }
 }
 
 impl<T: std::fmt::Debug> std::fmt::Display for FieldElement<T> {
@@ -172,6 +227,26 @@ where
     }
 }
 
+// Implement the neg operator.
+impl<T> std::ops::Neg for FieldElement<T>
+where
+    T: Number,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.__ensure_valid_range();
+
+        // p - 0 would be out of range, so the additive inverse of zero stays zero.
+        let num = if self.0 == T::zero() {
+            T::zero()
+        } else {
+            (self.1 - self.0) % self.1
+        };
+        FieldElement::new(num, self.1).unwrap()
+    }
+}
+
 // Implement the sub operator.
 impl<T> std::ops::Sub for FieldElement<T>
 where
@@ -188,8 +263,9 @@ where
             panic!("Sides are of different fields")
         }
 
-        let num = (self.0 - rhs.0) % self.1;
-        FieldElement::new(num, self.1).unwrap()
+        // Add the negation instead of subtracting directly, so this stays
+        // correct (and doesn't underflow) when rhs > self for unsigned T.
+        self + (-rhs)
     }
 }
 
@@ -242,6 +318,27 @@ where
     }
 }
 
+impl<T: Number> crate::primitives::field::Field for FieldElement<T> {
+    fn zero(&self) -> Self {
+        FieldElement::new(T::default(), self.1).unwrap()
+    }
+
+    fn one(&self) -> Self {
+        FieldElement::new(T::one(), self.1).unwrap()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == T::default()
+    }
+
+    fn inverse(&self) -> Self {
+        if self.is_zero() {
+            panic!("the zero element has no multiplicative inverse")
+        }
+        self.one() / *self
+    }
+}
+
 // Implement the eq operator.
 impl<T: std::cmp::PartialEq> std::cmp::PartialEq for FieldElement<T> {
     fn eq(&self, other: &Self) -> bool {
@@ -251,6 +348,30 @@ impl<T: std::cmp::PartialEq> std::cmp::PartialEq for FieldElement<T> {
 
 impl<T: PartialEq> Eq for FieldElement<T> {}
 
+/// Constant-time comparison and selection, so field arithmetic on secret
+/// values (e.g. a private key or nonce) doesn't leak them through
+/// data-dependent branching.
+#[cfg(feature = "constant_time")]
+mod constant_time {
+    use super::{FieldElement, Number};
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+    impl<T: Number + ConstantTimeEq> ConstantTimeEq for FieldElement<T> {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1)
+        }
+    }
+
+    impl<T: Number + ConditionallySelectable> ConditionallySelectable for FieldElement<T> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            FieldElement(
+                T::conditional_select(&a.0, &b.0, choice),
+                T::conditional_select(&a.1, &b.1, choice),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -327,6 +448,27 @@ mod tests {
         let _ = a - b;
     }
 
+    #[test]
+    fn sub_wraps_when_rhs_is_larger() {
+        let a = FieldElement(3_u8, 13_u8);
+        let b = FieldElement(5_u8, 13_u8);
+        let c = FieldElement(11_u8, 13_u8);
+        assert_eq!(a - b, c);
+    }
+
+    #[test]
+    fn neg_works() {
+        let a = FieldElement(5_u8, 13_u8);
+        let b = FieldElement(8_u8, 13_u8);
+        assert_eq!(-a, b);
+    }
+
+    #[test]
+    fn neg_of_zero_is_zero() {
+        let a = FieldElement(0_u8, 13_u8);
+        assert_eq!(-a, FieldElement(0_u8, 13_u8));
+    }
+
     #[test]
     fn mul_works() {
         let a = FieldElement(3_u8, 13_u8);
@@ -417,10 +559,94 @@ mod tests {
         let _ = a / b;
     }
 
+    #[test]
+    fn field_trait_identities_and_inverse_work() {
+        use crate::primitives::field::Field;
+
+        let a = FieldElement(7_u8, 13_u8);
+        assert_eq!(a.zero(), FieldElement(0_u8, 13_u8));
+        assert_eq!(a.one(), FieldElement(1_u8, 13_u8));
+        assert!(!a.is_zero());
+        assert!(a.zero().is_zero());
+        assert_eq!(a * a.inverse(), a.one());
+    }
+
+    #[test]
+    #[should_panic(expected = "the zero element has no multiplicative inverse")]
+    fn field_trait_inverse_panics_for_zero() {
+        use crate::primitives::field::Field;
+
+        FieldElement(0_u8, 13_u8).inverse();
+    }
+
     #[test]
     fn debug_and_display_impl_works() {
         let a = FieldElement(10_u8, 13_u8);
         assert_eq!(format!("{:?}", a), "10".to_string());
         assert_eq!(format!("{}", a), "10".to_string());
     }
+
+    #[test]
+    fn batch_invert_matches_individual_division() {
+        let mut elems = vec![
+            FieldElement::new(3_u8, 13_u8).unwrap(),
+            FieldElement::new(7_u8, 13_u8).unwrap(),
+            FieldElement::new(12_u8, 13_u8).unwrap(),
+        ];
+        let expected = vec![
+            FieldElement::new(1_u8, 13_u8).unwrap() / FieldElement::new(3_u8, 13_u8).unwrap(),
+            FieldElement::new(1_u8, 13_u8).unwrap() / FieldElement::new(7_u8, 13_u8).unwrap(),
+            FieldElement::new(1_u8, 13_u8).unwrap() / FieldElement::new(12_u8, 13_u8).unwrap(),
+        ];
+
+        FieldElement::batch_invert(&mut elems);
+
+        assert_eq!(elems, expected);
+    }
+
+    #[test]
+    fn batch_invert_leaves_zero_elements_untouched() {
+        let mut elems = vec![
+            FieldElement::new(0_u8, 13_u8).unwrap(),
+            FieldElement::new(5_u8, 13_u8).unwrap(),
+        ];
+
+        FieldElement::batch_invert(&mut elems);
+
+        assert_eq!(elems[0], FieldElement::new(0_u8, 13_u8).unwrap());
+        assert_eq!(
+            elems[1],
+            FieldElement::new(1_u8, 13_u8).unwrap() / FieldElement::new(5_u8, 13_u8).unwrap()
+        );
+    }
+
+    #[test]
+    fn batch_invert_on_empty_slice_is_a_no_op() {
+        let mut elems: Vec<FieldElement<u8>> = vec![];
+        FieldElement::batch_invert(&mut elems);
+        assert!(elems.is_empty());
+    }
+
+    #[cfg(feature = "constant_time")]
+    #[test]
+    fn ct_eq_agrees_with_partial_eq() {
+        use subtle::ConstantTimeEq;
+
+        let a = FieldElement(7_u8, 13_u8);
+        let b = FieldElement(7_u8, 13_u8);
+        let c = FieldElement(8_u8, 13_u8);
+        assert!(bool::from(a.ct_eq(&b)));
+        assert!(!bool::from(a.ct_eq(&c)));
+    }
+
+    #[cfg(feature = "constant_time")]
+    #[test]
+    fn conditional_select_picks_the_chosen_operand() {
+        use subtle::{Choice, ConditionallySelectable};
+
+        let a = FieldElement(7_u8, 13_u8);
+        let b = FieldElement(8_u8, 13_u8);
+        assert_eq!(FieldElement::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(FieldElement::conditional_select(&a, &b, Choice::from(1)), b);
+    }
 }